@@ -1,7 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -9,6 +12,74 @@ pub struct CacheEntry<T> {
     pub data: T,
     pub created_at: DateTime<Utc>,
     pub expires_at: Option<DateTime<Utc>>,
+    pub last_accessed: DateTime<Utc>,
+    pub access_count: u64,
+}
+
+/// Which entry an over-capacity `set` should evict to make room.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Oldest `created_at` goes first — the original behavior.
+    Fifo,
+    /// Oldest `last_accessed` goes first, so a hot key survives no matter
+    /// how early it was inserted.
+    Lru,
+    /// Lowest `access_count` goes first, favoring keys read repeatedly
+    /// over keys read once or twice.
+    Lfu,
+}
+
+/// Per-entry expiration policy, consulted instead of the uniform
+/// `default_ttl_seconds` when a `Cache` is built `with_expiry`. Letting a
+/// policy see the value itself (not just the key) supports value-dependent
+/// TTLs, and separating create/read/update lets a cache slide its expiry
+/// on access (see `SlidingExpiry`) without a bespoke method per policy.
+pub trait Expiry<T>: Send + Sync {
+    fn expire_after_create(&self, key: &str, value: &T, now: DateTime<Utc>) -> Option<Duration>;
+
+    fn expire_after_read(
+        &self,
+        key: &str,
+        value: &T,
+        now: DateTime<Utc>,
+        current_expires_at: Option<DateTime<Utc>>,
+    ) -> Option<Duration> {
+        let _ = (key, value, now);
+        current_expires_at.map(|expires_at| expires_at - now)
+    }
+
+    fn expire_after_update(
+        &self,
+        key: &str,
+        value: &T,
+        now: DateTime<Utc>,
+        current_expires_at: Option<DateTime<Utc>>,
+    ) -> Option<Duration> {
+        let _ = current_expires_at;
+        self.expire_after_create(key, value, now)
+    }
+}
+
+/// Resets the same TTL on every read, so a key stays alive as long as
+/// something keeps touching it instead of expiring on a fixed schedule.
+pub struct SlidingExpiry {
+    pub ttl: Duration,
+}
+
+impl<T> Expiry<T> for SlidingExpiry {
+    fn expire_after_create(&self, _key: &str, _value: &T, _now: DateTime<Utc>) -> Option<Duration> {
+        Some(self.ttl)
+    }
+
+    fn expire_after_read(
+        &self,
+        _key: &str,
+        _value: &T,
+        _now: DateTime<Utc>,
+        _current_expires_at: Option<DateTime<Utc>>,
+    ) -> Option<Duration> {
+        Some(self.ttl)
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -21,130 +92,576 @@ pub struct CacheStats {
     pub memory_size_bytes: usize,
 }
 
-pub struct Cache<T> {
-    data: Arc<RwLock<HashMap<String, CacheEntry<T>>>>,
-    hits: Arc<RwLock<u64>>,
-    misses: Arc<RwLock<u64>>,
-    evictions: Arc<RwLock<u64>>,
+type BackendFuture<'a, O> = Pin<Box<dyn Future<Output = O> + Send + 'a>>;
+
+/// Where a `Cache`'s entries actually live. `InMemoryBackend` (the
+/// default) keeps everything in a `HashMap` and loses it all on restart;
+/// `SledBackend` persists the same `CacheEntry<T>` to an embedded
+/// key-value store so a cache can rehydrate instead of starting cold.
+/// `Cache<T>` is generic over this so callers pick volatile vs durable
+/// per instance without touching any of its async call sites.
+pub trait CacheBackend<T>: Send + Sync {
+    fn get(&self, key: &str) -> BackendFuture<'_, Option<CacheEntry<T>>>;
+    fn set(&self, key: String, entry: CacheEntry<T>) -> BackendFuture<'_, ()>;
+    fn remove(&self, key: &str) -> BackendFuture<'_, Option<CacheEntry<T>>>;
+    fn iter_for_cleanup(&self) -> BackendFuture<'_, Vec<(String, CacheEntry<T>)>>;
+}
+
+/// Default backend: a plain in-memory map, gone on restart.
+pub struct InMemoryBackend<T> {
+    data: RwLock<HashMap<String, CacheEntry<T>>>,
+}
+
+impl<T> InMemoryBackend<T> {
+    pub fn new() -> Self {
+        Self {
+            data: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl<T> Default for InMemoryBackend<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> CacheBackend<T> for InMemoryBackend<T> {
+    fn get(&self, key: &str) -> BackendFuture<'_, Option<CacheEntry<T>>> {
+        let key = key.to_string();
+        Box::pin(async move { self.data.read().await.get(&key).cloned() })
+    }
+
+    fn set(&self, key: String, entry: CacheEntry<T>) -> BackendFuture<'_, ()> {
+        Box::pin(async move {
+            self.data.write().await.insert(key, entry);
+        })
+    }
+
+    fn remove(&self, key: &str) -> BackendFuture<'_, Option<CacheEntry<T>>> {
+        let key = key.to_string();
+        Box::pin(async move { self.data.write().await.remove(&key) })
+    }
+
+    fn iter_for_cleanup(&self) -> BackendFuture<'_, Vec<(String, CacheEntry<T>)>> {
+        Box::pin(async move {
+            self.data
+                .read()
+                .await
+                .iter()
+                .map(|(key, entry)| (key.clone(), entry.clone()))
+                .collect()
+        })
+    }
+}
+
+/// Persists every `CacheEntry<T>` (via its existing `Serialize`/
+/// `Deserialize` bounds) to an embedded `sled` key-value store, so a
+/// cache built on this backend survives a process restart instead of
+/// starting cold. Requires adding the `sled` crate as a dependency.
+pub struct SledBackend<T> {
+    tree: sled::Tree,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T> SledBackend<T> {
+    /// Opens (or creates) the on-disk database at `path` and the named
+    /// tree within it — one tree per cache instance keeps keys from
+    /// different caches sharing a `sled::Db` from colliding.
+    pub fn open(path: &std::path::Path, tree_name: &str) -> sled::Result<Self> {
+        let db = sled::open(path)?;
+        let tree = db.open_tree(tree_name)?;
+        Ok(Self {
+            tree,
+            _marker: std::marker::PhantomData,
+        })
+    }
+}
+
+impl<T> CacheBackend<T> for SledBackend<T>
+where
+    T: Serialize + for<'de> Deserialize<'de> + Clone + Send + Sync + 'static,
+{
+    fn get(&self, key: &str) -> BackendFuture<'_, Option<CacheEntry<T>>> {
+        let key = key.to_string();
+        Box::pin(async move {
+            let bytes = self.tree.get(key.as_bytes()).ok().flatten()?;
+            serde_json::from_slice(&bytes).ok()
+        })
+    }
+
+    fn set(&self, key: String, entry: CacheEntry<T>) -> BackendFuture<'_, ()> {
+        Box::pin(async move {
+            if let Ok(bytes) = serde_json::to_vec(&entry) {
+                let _ = self.tree.insert(key.as_bytes(), bytes);
+            }
+        })
+    }
+
+    fn remove(&self, key: &str) -> BackendFuture<'_, Option<CacheEntry<T>>> {
+        let key = key.to_string();
+        Box::pin(async move {
+            let bytes = self.tree.remove(key.as_bytes()).ok().flatten()?;
+            serde_json::from_slice(&bytes).ok()
+        })
+    }
+
+    fn iter_for_cleanup(&self) -> BackendFuture<'_, Vec<(String, CacheEntry<T>)>> {
+        Box::pin(async move {
+            self.tree
+                .iter()
+                .filter_map(|result| {
+                    let (key_bytes, value_bytes) = result.ok()?;
+                    let key = String::from_utf8(key_bytes.to_vec()).ok()?;
+                    let entry = serde_json::from_slice(&value_bytes).ok()?;
+                    Some((key, entry))
+                })
+                .collect()
+        })
+    }
+}
+
+pub struct Cache<T, B: CacheBackend<T> = InMemoryBackend<T>> {
+    backend: Arc<B>,
+    // 원래 RwLock<u64>였으나, get()의 읽기 잠금을 쥔 채로 이 카운터들에
+    // await하다 보니 단일 스레드 런타임에서 교착 가능성이 있었다.
+    // 잠금이 필요 없는 원자적 카운터로 바꿔 그 경합을 완전히 없앤다.
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
     max_entries: usize,
     default_ttl_seconds: u64,
+    expiry: Option<Arc<dyn Expiry<T>>>,
+    eviction_policy: EvictionPolicy,
+    // get_or_compute()의 단일 실행 보장을 위한 키별 자리표시자. 일반
+    // 잠금 해제에 await이 필요 없으므로 std Mutex로 충분하고, 패닉 시
+    // PendingGuard의 동기 Drop에서 바로 치울 수 있다.
+    pending: std::sync::Mutex<HashMap<String, Arc<tokio::sync::Mutex<Option<T>>>>>,
+    // 만료 시각(초 단위 epoch) -> 그 시각에 만료되는 키들의 역색인.
+    // cleanup_expired()/재니터가 `<= now`인 버킷만 훑으면 되므로, 만료된
+    // 항목 수에 비례하는 시간에 끝난다 (전체 테이블 크기와 무관). 영속
+    // 백엔드를 쓰더라도 이 색인 자체는 메모리에만 두고, with_backend()가
+    // 기동 시 백엔드를 훑어 다시 채운다.
+    expiry_index: RwLock<BTreeMap<i64, Vec<String>>>,
+}
+
+/// Clears this key's single-flight placeholder if `init()` panics or its
+/// task is cancelled before finishing, so the key doesn't stay stuck
+/// waiting on a slot nobody will ever fill.
+struct PendingGuard<'a, T: Clone + Send + Sync + 'static, B: CacheBackend<T>> {
+    cache: &'a Cache<T, B>,
+    key: &'a str,
+    completed: bool,
+}
+
+impl<'a, T: Clone + Send + Sync + 'static, B: CacheBackend<T>> Drop for PendingGuard<'a, T, B> {
+    fn drop(&mut self) {
+        if !self.completed {
+            let mut pending = self.cache.pending.lock().expect("pending lock poisoned");
+            pending.remove(self.key);
+        }
+    }
 }
 
-impl<T: Clone + Send + Sync + 'static> Cache<T> {
+impl<T: Clone + Send + Sync + 'static> Cache<T, InMemoryBackend<T>> {
     pub fn new(max_entries: usize, default_ttl_seconds: u64) -> Self {
+        Self::with_expiry(max_entries, default_ttl_seconds, None)
+    }
+
+    /// Same as `new`, but entries are (re)expired through `expiry` instead
+    /// of the uniform `default_ttl_seconds` / `set_with_ttl` override.
+    pub fn with_expiry(
+        max_entries: usize,
+        default_ttl_seconds: u64,
+        expiry: Option<Arc<dyn Expiry<T>>>,
+    ) -> Self {
+        Self::with_policy(max_entries, default_ttl_seconds, expiry, EvictionPolicy::Fifo)
+    }
+
+    /// Same as `with_expiry` plus a choice of which entry gets evicted
+    /// once `max_entries` is reached. Stays on the (empty, volatile)
+    /// in-memory backend — use `with_backend` for a durable one.
+    pub fn with_policy(
+        max_entries: usize,
+        default_ttl_seconds: u64,
+        expiry: Option<Arc<dyn Expiry<T>>>,
+        eviction_policy: EvictionPolicy,
+    ) -> Self {
         Self {
-            data: Arc::new(RwLock::new(HashMap::new())),
-            hits: Arc::new(RwLock::new(0)),
-            misses: Arc::new(RwLock::new(0)),
-            evictions: Arc::new(RwLock::new(0)),
+            backend: Arc::new(InMemoryBackend::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
             max_entries,
             default_ttl_seconds,
+            expiry,
+            eviction_policy,
+            pending: std::sync::Mutex::new(HashMap::new()),
+            expiry_index: RwLock::new(BTreeMap::new()),
         }
     }
-    
-    pub async fn get(&self, key: &str) -> Option<T> {
-        let data = self.data.read().await;
-        
-        if let Some(entry) = data.get(key) {
-            // 만료 확인
-            if let Some(expires_at) = entry.expires_at {
-                if Utc::now() > expires_at {
-                    drop(data);
-                    self.remove(key).await;
-                    *self.misses.write().await += 1;
-                    return None;
+}
+
+impl<T, B> Cache<T, B>
+where
+    T: Clone + Send + Sync + 'static,
+    B: CacheBackend<T>,
+{
+    /// Full constructor for a non-default backend (e.g. `SledBackend`).
+    /// Rehydrates the expiry index from whatever the backend already has
+    /// on disk, dropping anything that expired while the process was down
+    /// instead of serving it back out as if it were still fresh.
+    pub async fn with_backend(
+        max_entries: usize,
+        default_ttl_seconds: u64,
+        expiry: Option<Arc<dyn Expiry<T>>>,
+        eviction_policy: EvictionPolicy,
+        backend: B,
+    ) -> Self {
+        let cache = Self {
+            backend: Arc::new(backend),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+            max_entries,
+            default_ttl_seconds,
+            expiry,
+            eviction_policy,
+            pending: std::sync::Mutex::new(HashMap::new()),
+            expiry_index: RwLock::new(BTreeMap::new()),
+        };
+
+        let now = Utc::now();
+        for (key, entry) in cache.backend.iter_for_cleanup().await {
+            match entry.expires_at {
+                Some(expires_at) if expires_at <= now => {
+                    let _ = cache.backend.remove(&key).await;
                 }
+                Some(expires_at) => {
+                    cache
+                        .expiry_index
+                        .write()
+                        .await
+                        .entry(expires_at.timestamp())
+                        .or_default()
+                        .push(key);
+                }
+                None => {}
             }
-            
-            *self.hits.write().await += 1;
-            Some(entry.data.clone())
-        } else {
-            *self.misses.write().await += 1;
-            None
         }
+
+        cache
+    }
+
+    /// Picks the key the eviction policy would remove to make room.
+    fn select_eviction_victim(&self, entries: &[(String, CacheEntry<T>)]) -> Option<String> {
+        match self.eviction_policy {
+            EvictionPolicy::Fifo => entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.created_at)
+                .map(|(key, _)| key.clone()),
+            EvictionPolicy::Lru => entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_accessed)
+                .map(|(key, _)| key.clone()),
+            EvictionPolicy::Lfu => entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.access_count)
+                .map(|(key, _)| key.clone()),
+        }
+    }
+
+    /// Keeps `expiry_index` in sync with a key's `expires_at` change:
+    /// drops the stale bucket reference (if any) and adds the new one
+    /// (skipping entries that never expire, i.e. `expires_at == None`).
+    async fn reindex_expiry(
+        &self,
+        key: &str,
+        previous_expires_at: Option<DateTime<Utc>>,
+        new_expires_at: Option<DateTime<Utc>>,
+    ) {
+        if previous_expires_at == new_expires_at {
+            return;
+        }
+
+        let mut index = self.expiry_index.write().await;
+
+        if let Some(previous) = previous_expires_at {
+            let bucket = previous.timestamp();
+            if let Some(keys) = index.get_mut(&bucket) {
+                keys.retain(|k| k != key);
+                if keys.is_empty() {
+                    index.remove(&bucket);
+                }
+            }
+        }
+
+        if let Some(next) = new_expires_at {
+            index.entry(next.timestamp()).or_default().push(key.to_string());
+        }
+    }
+
+    pub async fn get(&self, key: &str) -> Option<T> {
+        let Some(mut entry) = self.backend.get(key).await else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        };
+
+        // 만료 확인
+        if let Some(expires_at) = entry.expires_at {
+            if Utc::now() > expires_at {
+                self.backend.remove(key).await;
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                self.reindex_expiry(key, Some(expires_at), None).await;
+                return None;
+            }
+        }
+
+        let now = Utc::now();
+        let previous_expires_at = entry.expires_at;
+        if let Some(expiry) = &self.expiry {
+            entry.expires_at = expiry
+                .expire_after_read(key, &entry.data, now, entry.expires_at)
+                .map(|duration| now + duration);
+        }
+        entry.last_accessed = now;
+        entry.access_count += 1;
+        let new_expires_at = entry.expires_at;
+
+        let value = entry.data.clone();
+        self.backend.set(key.to_string(), entry).await;
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        self.reindex_expiry(key, previous_expires_at, new_expires_at).await;
+        Some(value)
     }
-    
+
     pub async fn set(&self, key: String, value: T) {
         self.set_with_ttl(key, value, None).await;
     }
-    
+
     pub async fn set_with_ttl(&self, key: String, value: T, ttl_seconds: Option<u64>) {
-        let mut data = self.data.write().await;
-        
+        let mut entries = self.backend.iter_for_cleanup().await;
+
         // 크기 제한 확인
-        if data.len() >= self.max_entries && !data.contains_key(&key) {
-            // 가장 오래된 항목 제거 (LRU 스타일)
-            if let Some((oldest_key, _)) = data.iter()
-                .min_by_key(|(_, entry)| entry.created_at)
-                .map(|(k, v)| (k.clone(), v.clone())) {
-                data.remove(&oldest_key);
-                *self.evictions.write().await += 1;
+        let mut evicted: Option<(String, Option<DateTime<Utc>>)> = None;
+        let already_present = entries.iter().any(|(k, _)| k == &key);
+        if entries.len() >= self.max_entries && !already_present {
+            if let Some(victim_key) = self.select_eviction_victim(&entries) {
+                if let Some(victim_entry) = self.backend.remove(&victim_key).await {
+                    evicted = Some((victim_key.clone(), victim_entry.expires_at));
+                }
+                entries.retain(|(k, _)| k != &victim_key);
+                self.evictions.fetch_add(1, Ordering::Relaxed);
             }
         }
-        
-        let ttl = ttl_seconds.unwrap_or(self.default_ttl_seconds);
-        let expires_at = if ttl > 0 {
-            Some(Utc::now() + chrono::Duration::seconds(ttl as i64))
+
+        let now = Utc::now();
+        let existing = entries.iter().find(|(k, _)| k == &key).map(|(_, entry)| entry);
+        let existing_access_count = existing.map(|entry| entry.access_count).unwrap_or(0);
+        let previous_expires_at = existing.and_then(|entry| entry.expires_at);
+        let expires_at = if let Some(expiry) = &self.expiry {
+            let duration = if existing.is_some() {
+                expiry.expire_after_update(&key, &value, now, previous_expires_at)
+            } else {
+                expiry.expire_after_create(&key, &value, now)
+            };
+            duration.map(|duration| now + duration)
         } else {
-            None
+            let ttl = ttl_seconds.unwrap_or(self.default_ttl_seconds);
+            if ttl > 0 {
+                Some(now + chrono::Duration::seconds(ttl as i64))
+            } else {
+                None
+            }
         };
-        
+
         let entry = CacheEntry {
             data: value,
-            created_at: Utc::now(),
+            created_at: now,
             expires_at,
+            last_accessed: now,
+            access_count: existing_access_count,
+        };
+
+        self.backend.set(key.clone(), entry).await;
+
+        if let Some((victim_key, victim_expires_at)) = evicted {
+            self.reindex_expiry(&victim_key, victim_expires_at, None).await;
+        }
+        self.reindex_expiry(&key, previous_expires_at, expires_at).await;
+    }
+
+    /// Looks `key` up, and on a miss runs `init` to fill it — guaranteeing
+    /// `init` runs exactly once even if many callers miss on the same key
+    /// at once, instead of letting them all recompute it concurrently
+    /// (the "thundering herd"/dogpile case a cold `SESSION_CACHE` key hits).
+    pub async fn get_or_compute<F, Fut>(&self, key: String, ttl: Option<u64>, init: F) -> T
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = T>,
+    {
+        if let Some(value) = self.get(&key).await {
+            return value;
+        }
+
+        let placeholder = {
+            let mut pending = self.pending.lock().expect("pending lock poisoned");
+            pending
+                .entry(key.clone())
+                .or_insert_with(|| Arc::new(tokio::sync::Mutex::new(None)))
+                .clone()
         };
-        
-        data.insert(key, entry);
+
+        // 먼저 잠금을 쥔 호출자만 init을 실행한다; 이후 도착한 호출자는
+        // 여기서 대기하다가 값이 채워진 걸 보고 그대로 복제해 간다.
+        let mut slot = placeholder.lock().await;
+        if let Some(value) = slot.as_ref() {
+            return value.clone();
+        }
+        // 앞선 호출자가 우리의 미스 직후 값을 저장하고 자리표시자를 치웠다면
+        // 여기서 새 자리표시자를 잡게 되므로, init을 다시 돌리기 전에 확인한다.
+        if let Some(entry) = self.backend.get(&key).await {
+            if entry.expires_at.map_or(true, |expires_at| Utc::now() <= expires_at) {
+                return entry.data;
+            }
+        }
+
+        let mut guard = PendingGuard { cache: self, key: &key, completed: false };
+        let value = init().await;
+        guard.completed = true;
+        drop(guard);
+
+        *slot = Some(value.clone());
+        drop(slot);
+
+        self.set_with_ttl(key.clone(), value.clone(), ttl).await;
+        self.pending.lock().expect("pending lock poisoned").remove(&key);
+
+        value
     }
-    
+
     pub async fn remove(&self, key: &str) -> Option<T> {
-        let mut data = self.data.write().await;
-        data.remove(key).map(|entry| entry.data)
+        let removed = self.backend.remove(key).await;
+
+        if let Some(entry) = &removed {
+            self.reindex_expiry(key, entry.expires_at, None).await;
+        }
+
+        removed.map(|entry| entry.data)
     }
-    
+
+    /// Snapshot every live entry as a plain map, ignoring hit/miss
+    /// accounting. Used by callers that need the full cached state at
+    /// once (e.g. a dashboard refresh) rather than one key at a time.
+    pub async fn entries(&self) -> HashMap<String, T> {
+        self.backend
+            .iter_for_cleanup()
+            .await
+            .into_iter()
+            .filter(|(_, entry)| entry.expires_at.map_or(true, |expires_at| Utc::now() <= expires_at))
+            .map(|(key, entry)| (key, entry.data))
+            .collect()
+    }
+
     pub async fn clear(&self) {
-        let mut data = self.data.write().await;
-        data.clear();
+        for (key, _) in self.backend.iter_for_cleanup().await {
+            self.backend.remove(&key).await;
+        }
+        self.expiry_index.write().await.clear();
     }
-    
+
+    /// Removes every key whose bucket has already passed, reading only
+    /// the buckets `<= now` instead of the whole table — the sweep cost
+    /// is proportional to how many entries actually expired, not to the
+    /// cache's total size.
     pub async fn cleanup_expired(&self) {
-        let mut data = self.data.write().await;
-        let now = Utc::now();
+        let expired_keys = self.drain_expired_buckets(usize::MAX).await;
+        if expired_keys.is_empty() {
+            return;
+        }
+
+        for key in &expired_keys {
+            self.backend.remove(key).await;
+        }
+
+        self.evictions.fetch_add(expired_keys.len() as u64, Ordering::Relaxed);
+    }
+
+    /// Pops whole expired buckets off the front of `expiry_index` (oldest
+    /// first) until at least `budget` keys have been collected or no
+    /// bucket is due yet, removing those buckets from the index as it
+    /// goes. Does not touch the backend — callers remove the returned keys.
+    async fn drain_expired_buckets(&self, budget: usize) -> Vec<String> {
+        let now_epoch = Utc::now().timestamp();
+        let mut index = self.expiry_index.write().await;
+
+        let due: Vec<i64> = index.range(..=now_epoch).map(|(&bucket, _)| bucket).collect();
+
         let mut expired_keys = Vec::new();
-        
-        for (key, entry) in data.iter() {
-            if let Some(expires_at) = entry.expires_at {
-                if now > expires_at {
-                    expired_keys.push(key.clone());
-                }
+        for bucket in due {
+            if expired_keys.len() >= budget {
+                break;
+            }
+            if let Some(keys) = index.remove(&bucket) {
+                expired_keys.extend(keys);
             }
         }
-        
-        for key in expired_keys {
-            data.remove(&key);
-            *self.evictions.write().await += 1;
-        }
+
+        expired_keys
+    }
+
+    /// Spawns a task that wakes every `interval` and sweeps a bounded
+    /// batch of expired entries, so callers no longer have to remember to
+    /// call `cleanup_expired()` by hand. Bounding the batch (instead of
+    /// draining every due bucket at once) keeps one tick from stalling on
+    /// a cache that built up a large backlog of expired keys. Holds only
+    /// a `Weak` reference, so once every `Arc<Cache<T, B>>` is dropped the
+    /// task notices on its next tick and exits instead of keeping the
+    /// cache alive forever.
+    pub fn start_janitor(self: &Arc<Self>, interval: std::time::Duration) -> tauri::async_runtime::JoinHandle<()> {
+        const JANITOR_BATCH_SIZE: usize = 256;
+
+        let weak = Arc::downgrade(self);
+        tauri::async_runtime::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let Some(cache) = weak.upgrade() else {
+                    break;
+                };
+
+                let expired_keys = cache.drain_expired_buckets(JANITOR_BATCH_SIZE).await;
+                if expired_keys.is_empty() {
+                    continue;
+                }
+
+                for key in &expired_keys {
+                    cache.backend.remove(key).await;
+                }
+
+                cache.evictions.fetch_add(expired_keys.len() as u64, Ordering::Relaxed);
+            }
+        })
     }
-    
+
     pub async fn get_stats(&self) -> CacheStats {
-        let data = self.data.read().await;
-        let hits = *self.hits.read().await;
-        let misses = *self.misses.read().await;
-        let evictions = *self.evictions.read().await;
-        
+        let entries = self.backend.iter_for_cleanup().await;
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let evictions = self.evictions.load(Ordering::Relaxed);
+
         let total_requests = hits + misses;
         let hit_rate = if total_requests > 0 {
             (hits as f64 / total_requests as f64) * 100.0
         } else {
             0.0
         };
-        
+
         // 대략적인 메모리 사용량 계산 (실제로는 더 정확한 계산 필요)
-        let memory_size_bytes = data.len() * std::mem::size_of::<CacheEntry<T>>();
-        
+        let memory_size_bytes = entries.len() * std::mem::size_of::<CacheEntry<T>>();
+
         CacheStats {
-            total_entries: data.len(),
+            total_entries: entries.len(),
             hits,
             misses,
             evictions,
@@ -154,8 +671,29 @@ impl<T: Clone + Send + Sync + 'static> Cache<T> {
     }
 }
 
+/// Where `CONFIG_CACHE`'s sled tree lives on disk, mirroring the plain
+/// relative-directory convention `session_log::LOG_DIR` already uses.
+const CONFIG_CACHE_DB_PATH: &str = "data/config_cache";
+
 // 전역 캐시 인스턴스들
+// Arc로 감싸 start_janitor()가 Weak 참조를 들고 백그라운드에서 돌 수 있게 한다.
 lazy_static::lazy_static! {
-    pub static ref SESSION_CACHE: Cache<String> = Cache::new(100, 300); // 5분 TTL
-    pub static ref CONFIG_CACHE: Cache<String> = Cache::new(50, 3600);  // 1시간 TTL
-}
\ No newline at end of file
+    // 5분 슬라이딩 TTL: 세션이 계속 조회되는 한 만료되지 않는다.
+    pub static ref SESSION_CACHE: Arc<Cache<String>> = Arc::new(Cache::with_expiry(
+        100,
+        300,
+        Some(Arc::new(SlidingExpiry { ttl: Duration::seconds(300) }) as Arc<dyn Expiry<String>>),
+    ));
+    // sled에 영속화해 재시작해도 콜드 재계산 없이 바로 복구된다. 1시간 TTL.
+    pub static ref CONFIG_CACHE: Arc<Cache<String, SledBackend<String>>> = {
+        let backend = SledBackend::open(std::path::Path::new(CONFIG_CACHE_DB_PATH), "config_cache")
+            .expect("failed to open config cache sled db");
+        Arc::new(tauri::async_runtime::block_on(Cache::with_backend(
+            50,
+            3600,
+            None,
+            EvictionPolicy::Fifo,
+            backend,
+        )))
+    };
+}
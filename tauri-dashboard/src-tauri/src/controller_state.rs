@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::cache::Cache;
+use crate::error::BridgeError;
+use crate::events::EVENT_MANAGER;
+
+/// Lifecycle of one session's controller. Replaces the ad-hoc
+/// "Active"/"Ready"/"Not Available"/"Error" strings `get_controller_status`
+/// used to return, so callers can reject illegal transitions (e.g.
+/// starting an already-`Starting` controller) before touching Python.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", content = "reason")]
+pub enum ControllerState {
+    Idle,
+    Starting,
+    Running,
+    Stopping,
+    Stopped,
+    Failed(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControllerStateEntry {
+    pub state: ControllerState,
+    pub transitioned_at: DateTime<Utc>,
+}
+
+// 전역 상태 캐시: TTL 0은 "만료 없음"을 의미한다 (cache.rs 참고).
+lazy_static::lazy_static! {
+    pub static ref CONTROLLER_STATE_CACHE: Cache<ControllerStateEntry> = Cache::new(500, 0);
+    // 세션별 전이 잠금: 두 start_controller 호출이 동시에 같은 세션을
+    // Idle로 읽고 둘 다 통과해버리는 걸 막기 위해, 읽기-검사-쓰기를
+    // 세션당 하나씩 직렬화한다.
+    static ref TRANSITION_LOCKS: std::sync::Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>> =
+        std::sync::Mutex::new(HashMap::new());
+}
+
+fn session_lock(session_name: &str) -> Arc<tokio::sync::Mutex<()>> {
+    let mut locks = TRANSITION_LOCKS.lock().expect("transition locks poisoned");
+    locks
+        .entry(session_name.to_string())
+        .or_insert_with(|| Arc::new(tokio::sync::Mutex::new(())))
+        .clone()
+}
+
+fn is_allowed(from: &ControllerState, to: &ControllerState) -> bool {
+    use ControllerState::*;
+    if matches!(to, Failed(_)) {
+        // A controller can fail out of any state.
+        return true;
+    }
+    matches!(
+        (from, to),
+        (Idle, Starting)
+            | (Stopped, Starting)
+            | (Failed(_), Starting)
+            | (Starting, Running)
+            | (Running, Stopping)
+            | (Stopping, Stopped)
+    )
+}
+
+/// Move `session_name`'s cached controller state to `to`, rejecting the
+/// transition if it isn't reachable from the current cached state. The
+/// read-check-write is serialized per session so two concurrent callers
+/// (e.g. two `start_controller` calls) can't both observe `Idle` and both
+/// pass the check.
+pub async fn transition(session_name: &str, to: ControllerState) -> Result<(), BridgeError> {
+    let lock = session_lock(session_name);
+    let _guard = lock.lock().await;
+
+    let current = CONTROLLER_STATE_CACHE
+        .get(session_name)
+        .await
+        .map(|entry| entry.state)
+        .unwrap_or(ControllerState::Idle);
+
+    if !is_allowed(&current, &to) {
+        return Err(BridgeError::Protocol(format!(
+            "illegal controller transition for '{}': {:?} -> {:?}",
+            session_name, current, to
+        )));
+    }
+
+    let entry = ControllerStateEntry {
+        state: to.clone(),
+        transitioned_at: Utc::now(),
+    };
+    CONTROLLER_STATE_CACHE.set(session_name.to_string(), entry).await;
+    EVENT_MANAGER
+        .emit_session_update(session_name, "", &format!("{:?}", to))
+        .await;
+
+    Ok(())
+}
+
+pub async fn current(session_name: &str) -> ControllerState {
+    CONTROLLER_STATE_CACHE
+        .get(session_name)
+        .await
+        .map(|entry| entry.state)
+        .unwrap_or(ControllerState::Idle)
+}
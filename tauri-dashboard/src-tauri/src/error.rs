@@ -0,0 +1,68 @@
+use serde::Serialize;
+use thiserror::Error;
+use tokio::sync::broadcast;
+
+/// Structured error type for every bridge command, replacing the old
+/// `Result<_, String>` + string-matching on `"error"` in the Python
+/// output. `Serialize` lets the frontend branch on `type` instead of
+/// parsing a human-readable message.
+#[derive(Debug, Error, Clone, Serialize)]
+#[serde(tag = "type", content = "detail")]
+pub enum BridgeError {
+    #[error("failed to spawn python process: {0}")]
+    Spawn(String),
+    #[error("io error: {0}")]
+    Io(String),
+    #[error("python exited with code {code}: {stderr}")]
+    PythonExit { code: i32, stderr: String },
+    #[error("bridge protocol error: {0}")]
+    Protocol(String),
+    #[error("failed to deserialize python response: {0}")]
+    Deserialize(String),
+    #[error("python raised an exception: {traceback}")]
+    PythonRaised { traceback: String },
+}
+
+impl From<serde_json::Error> for BridgeError {
+    fn from(e: serde_json::Error) -> Self {
+        BridgeError::Deserialize(e.to_string())
+    }
+}
+
+/// Global channel that any failing bridge command pushes its `BridgeError`
+/// into. A single consumer task (started in `main.rs`) drains it and
+/// forwards each error to the UI via `EventManager::emit_notification`,
+/// so backend failures surface as a live toast regardless of which
+/// command triggered them.
+#[derive(Clone)]
+pub struct ErrorChannel {
+    sender: broadcast::Sender<BridgeError>,
+}
+
+impl ErrorChannel {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(64);
+        Self { sender }
+    }
+
+    pub fn report(&self, error: BridgeError) {
+        // No receiver yet (e.g. during startup) is fine; the error is
+        // still returned to the caller, just not toasted.
+        let _ = self.sender.send(error);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<BridgeError> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for ErrorChannel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// 전역 에러 채널: 어떤 커맨드가 실패하든 하나의 소비자가 토스트로 전달한다.
+lazy_static::lazy_static! {
+    pub static ref ERROR_CHANNEL: ErrorChannel = ErrorChannel::new();
+}
@@ -26,6 +26,7 @@ pub struct MetricUpdatePayload {
     pub timestamp: DateTime<Utc>,
 }
 
+#[derive(Clone)]
 pub struct EventManager {
     window: Arc<RwLock<Option<Window>>>,
 }
@@ -96,4 +97,10 @@ impl Default for EventManager {
     fn default() -> Self {
         Self::new()
     }
+}
+
+// 전역 이벤트 매니저: 커맨드 핸들러가 아닌 코드(세션 로그 기록 등)에서도
+// 같은 창으로 이벤트를 내보낼 수 있도록 한다.
+lazy_static::lazy_static! {
+    pub static ref EVENT_MANAGER: EventManager = EventManager::new();
 }
\ No newline at end of file
@@ -0,0 +1,119 @@
+use std::sync::RwLock as StdRwLock;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, GlobalShortcutManager, Manager};
+
+use crate::notifications::notify_controller_status_change;
+use crate::python_bridge;
+use crate::python_runtime::PythonRuntime;
+
+/// Accelerators for the quick-action hotkeys, stored in `AppConfig` so
+/// they survive `get_app_config`/`save_app_config` round-trips.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HotkeyBindings {
+    pub start_all: String,
+    pub stop_all: String,
+    pub restart_focused: String,
+}
+
+impl Default for HotkeyBindings {
+    fn default() -> Self {
+        Self {
+            start_all: "CmdOrCtrl+Shift+S".to_string(),
+            stop_all: "CmdOrCtrl+Shift+X".to_string(),
+            restart_focused: "CmdOrCtrl+Shift+R".to_string(),
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref HOTKEYS: StdRwLock<HotkeyBindings> = StdRwLock::new(HotkeyBindings::default());
+    static ref FOCUSED_SESSION: StdRwLock<Option<String>> = StdRwLock::new(None);
+}
+
+pub fn current_bindings() -> HotkeyBindings {
+    HOTKEYS.read().expect("hotkeys lock poisoned").clone()
+}
+
+pub fn set_bindings(bindings: HotkeyBindings) {
+    *HOTKEYS.write().expect("hotkeys lock poisoned") = bindings;
+}
+
+/// Tracks which session's pane is currently focused in the UI so the
+/// "restart focused session" hotkey knows which controller to hit.
+pub fn set_focused_session(session_name: Option<String>) {
+    *FOCUSED_SESSION.write().expect("focused session lock poisoned") = session_name;
+}
+
+/// Frontend-facing hook for `set_focused_session`, called whenever the UI
+/// switches which session's pane has focus (or loses focus entirely).
+/// Without this, `FOCUSED_SESSION` never leaves `None` and the "restart
+/// focused session" hotkey can never find a session to act on.
+#[tauri::command]
+pub async fn report_focused_session(session_name: Option<String>) -> Result<(), String> {
+    set_focused_session(session_name);
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum HotkeyAction {
+    StartAll,
+    StopAll,
+    RestartFocused,
+}
+
+/// Registers the configured accelerators against `app`. A slightly
+/// malformed or conflicting accelerator is logged and skipped rather
+/// than panicking, so one bad binding doesn't take the rest down.
+pub fn register_global_shortcuts(app: &AppHandle) {
+    let bindings = current_bindings();
+    let mut manager = app.global_shortcut_manager();
+
+    for (accelerator, action) in [
+        (bindings.start_all, HotkeyAction::StartAll),
+        (bindings.stop_all, HotkeyAction::StopAll),
+        (bindings.restart_focused, HotkeyAction::RestartFocused),
+    ] {
+        let handle = app.clone();
+        let accelerator_for_log = accelerator.clone();
+        let result = manager.register(&accelerator, move || {
+            let handle = handle.clone();
+            tauri::async_runtime::spawn(async move {
+                dispatch(&handle, action).await;
+            });
+        });
+
+        if let Err(e) = result {
+            eprintln!(
+                "failed to register hotkey '{}' ({:?}): {}",
+                accelerator_for_log, action, e
+            );
+        }
+    }
+}
+
+async fn dispatch(app: &AppHandle, action: HotkeyAction) {
+    let runtime = app.state::<PythonRuntime>();
+
+    match action {
+        HotkeyAction::StartAll => {
+            let count = python_bridge::start_all_controllers(runtime).await.unwrap_or(0);
+            let _ = notify_controller_status_change(app, "all sessions", &format!("started {} controllers", count));
+        }
+        HotkeyAction::StopAll => {
+            let count = python_bridge::stop_all_controllers(runtime).await.unwrap_or(0);
+            let _ = notify_controller_status_change(app, "all sessions", &format!("stopped {} controllers", count));
+        }
+        HotkeyAction::RestartFocused => {
+            let Some(session_name) = FOCUSED_SESSION.read().expect("focused session lock poisoned").clone() else {
+                let _ = notify_controller_status_change(app, "hotkey", "no focused session to restart");
+                return;
+            };
+            let restarted = python_bridge::restart_claude_pane(session_name.clone(), runtime)
+                .await
+                .unwrap_or(false);
+            let status = if restarted { "restarted" } else { "restart failed" };
+            let _ = notify_controller_status_change(app, &session_name, status);
+        }
+    }
+}
@@ -1,17 +1,83 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod python_bridge;
+mod python_runtime;
+mod error;
 mod events;
+mod session_log;
+mod controller_state;
+mod metrics;
 mod cache;
 mod notifications;
+mod hotkeys;
 
 use python_bridge::*;
-use events::EventManager;
+use python_runtime::PythonRuntime;
+use error::ERROR_CHANNEL;
+use events::EVENT_MANAGER;
 use notifications::*;
+use tauri::{CustomMenuItem, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu, SystemTrayMenuItem};
+
+/// Number of warm Python workers kept in the pool. Each one has already
+/// imported SessionManager/ClaudeManager/TmuxManager at boot.
+const PYTHON_WORKER_POOL_SIZE: usize = 4;
+
+/// Tray menu listing the bulk actions plus one start/stop pair per
+/// session known at the time it was built.
+fn build_tray_menu(sessions: &[SessionInfo]) -> SystemTrayMenu {
+    let mut menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new("start_all", "Start All Controllers"))
+        .add_item(CustomMenuItem::new("stop_all", "Stop All Controllers"))
+        .add_native_item(SystemTrayMenuItem::Separator);
+
+    for session in sessions {
+        menu = menu
+            .add_item(CustomMenuItem::new(
+                format!("start:{}", session.session_name),
+                format!("Start {}", session.session_name),
+            ))
+            .add_item(CustomMenuItem::new(
+                format!("stop:{}", session.session_name),
+                format!("Stop {}", session.session_name),
+            ));
+    }
+
+    menu.add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new("quit", "Quit"))
+}
+
+fn handle_tray_event(app: &tauri::AppHandle, event: SystemTrayEvent) {
+    let SystemTrayEvent::MenuItemClick { id, .. } = event else {
+        return;
+    };
+    let app = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let runtime = app.state::<PythonRuntime>();
+        match id.as_str() {
+            "quit" => std::process::exit(0),
+            "start_all" => {
+                let _ = start_all_controllers(runtime).await;
+            }
+            "stop_all" => {
+                let _ = stop_all_controllers(runtime).await;
+            }
+            other => {
+                if let Some(session_name) = other.strip_prefix("start:") {
+                    let _ = start_controller(session_name.to_string(), runtime).await;
+                } else if let Some(session_name) = other.strip_prefix("stop:") {
+                    let _ = stop_controller(session_name.to_string(), runtime).await;
+                }
+            }
+        }
+    });
+}
 
 fn main() {
     tauri::Builder::default()
-        .manage(EventManager::new())
+        .manage(EVENT_MANAGER.clone())
+        .system_tray(SystemTray::new().with_menu(build_tray_menu(&[])))
+        .on_system_tray_event(handle_tray_event)
         .invoke_handler(tauri::generate_handler![
             get_all_sessions,
             get_controller_status,
@@ -21,12 +87,16 @@ fn main() {
             get_app_config,
             save_app_config,
             get_session_logs,
+            clear_session_logs,
             get_metrics_data,
+            get_metrics_summary,
+            get_all_controller_states,
             setup_tmux_session,
             teardown_tmux_session,
-            show_notification
+            show_notification,
+            hotkeys::report_focused_session
         ])
-        .setup(|_app| {
+        .setup(|app| {
             // 초기 설정
             // WebKit deprecation warning 억제를 위한 환경 변수 설정
             #[cfg(target_os = "linux")]
@@ -34,7 +104,62 @@ fn main() {
                 // WebKit GTK 경고 메시지 억제
                 std::env::set_var("WEBKIT_DISABLE_COMPOSITING_MODE", "1");
             }
-            
+
+            let runtime = tauri::async_runtime::block_on(PythonRuntime::new(PYTHON_WORKER_POOL_SIZE))
+                .expect("failed to start Python worker pool");
+            app.manage(runtime);
+
+            // EVENT_MANAGER가 실제 창으로 이벤트를 내보낼 수 있도록 연결한다.
+            // 이게 없으면 emit_session_update/emit_log_update/emit_metric_update/
+            // emit_notification이 전부 window == None 분기로 빠져 조용히 무시된다.
+            let main_window = app.get_window("main").expect("main window not found");
+            tauri::async_runtime::block_on(EVENT_MANAGER.set_window(main_window));
+
+            // 시작 시점의 세션 목록으로 트레이 메뉴를 채운다 (메뉴는 여기서만
+            // 만들어지므로 이후 변경은 다음 재시작 때에야 반영된다).
+            let sessions = {
+                let runtime_state = app.state::<PythonRuntime>();
+                tauri::async_runtime::block_on(get_all_sessions(runtime_state)).unwrap_or_default()
+            };
+            if let Err(e) = app.tray_handle().set_menu(build_tray_menu(&sessions)) {
+                eprintln!("failed to populate tray menu: {}", e);
+            }
+
+            hotkeys::register_global_shortcuts(&app.handle());
+
+            // 각 캐시마다 자체 주기로 만료된 항목을 쓸어내는 백그라운드
+            // 재니터. Cache가 Weak 참조만 쥐고 있으므로 전역 인스턴스가
+            // 살아있는 한 계속 돈다.
+            cache::SESSION_CACHE.start_janitor(std::time::Duration::from_secs(60));
+            cache::CONFIG_CACHE.start_janitor(std::time::Duration::from_secs(300));
+
+            // 백엔드 에러를 창에 토스트로 전달하는 단일 소비자
+            let mut errors = ERROR_CHANNEL.subscribe();
+            tauri::async_runtime::spawn(async move {
+                while let Ok(error) = errors.recv().await {
+                    EVENT_MANAGER
+                        .emit_notification("Backend Error", &error.to_string(), "error")
+                        .await;
+                }
+            });
+
+            // 메트릭 폴러: refresh_interval마다 깨어나되 auto_refresh가
+            // 꺼져 있으면 이번 틱은 건너뛴다.
+            let app_handle = app.handle();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    let interval_secs = metrics::REFRESH_INTERVAL_SECS.load(std::sync::atomic::Ordering::Relaxed);
+                    tokio::time::sleep(std::time::Duration::from_secs(interval_secs.max(1))).await;
+
+                    if !metrics::AUTO_REFRESH.load(std::sync::atomic::Ordering::Relaxed) {
+                        continue;
+                    }
+
+                    let runtime = app_handle.state::<PythonRuntime>();
+                    metrics::poll_once(&runtime).await;
+                }
+            });
+
             Ok(())
         })
         .run(tauri::generate_context!())
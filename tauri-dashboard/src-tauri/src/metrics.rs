@@ -0,0 +1,200 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use crate::events::EVENT_MANAGER;
+use crate::python_bridge::SessionInfo;
+use crate::python_runtime::PythonRuntime;
+
+/// Samples kept per session before the oldest is evicted. The longest
+/// requested window is "24h"; at the fastest configurable poll (1s)
+/// that's 86400 samples, so size the ring to that worst case rather than
+/// silently truncating older history out of the "24h" rollup.
+const RING_CAPACITY: usize = 86_400;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricSample {
+    pub timestamp: DateTime<Utc>,
+    pub response_time: f64,
+    pub prompts_per_minute: f64,
+}
+
+/// Per-session aggregates over a requested window, rather than the raw
+/// sample series — what `get_metrics_data` was asked to return.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricRollup {
+    pub session_name: String,
+    pub min_response_time: f64,
+    pub max_response_time: f64,
+    pub avg_response_time: f64,
+    pub total_prompts_per_minute: f64,
+    pub sample_count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MetricsSummary {
+    pub session_count: usize,
+    pub avg_response_time: f64,
+    pub total_prompts_per_minute: f64,
+}
+
+struct MetricsStore {
+    samples: RwLock<HashMap<String, VecDeque<MetricSample>>>,
+}
+
+impl MetricsStore {
+    fn new() -> Self {
+        Self {
+            samples: RwLock::new(HashMap::new()),
+        }
+    }
+
+    async fn push(&self, session_name: &str, sample: MetricSample) {
+        let mut samples = self.samples.write().await;
+        let buffer = samples.entry(session_name.to_string()).or_default();
+        if buffer.len() >= RING_CAPACITY {
+            buffer.pop_front();
+        }
+        buffer.push_back(sample);
+    }
+
+    async fn window(&self, since: DateTime<Utc>) -> Vec<(String, MetricSample)> {
+        let samples = self.samples.read().await;
+        samples
+            .iter()
+            .flat_map(|(session, buf)| {
+                buf.iter()
+                    .filter(|s| s.timestamp >= since)
+                    .map(move |s| (session.clone(), s.clone()))
+            })
+            .collect()
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref METRICS_STORE: MetricsStore = MetricsStore::new();
+    pub static ref AUTO_REFRESH: AtomicBool = AtomicBool::new(true);
+    pub static ref REFRESH_INTERVAL_SECS: AtomicU64 = AtomicU64::new(2);
+}
+
+pub fn set_auto_refresh(enabled: bool) {
+    AUTO_REFRESH.store(enabled, Ordering::Relaxed);
+}
+
+pub fn set_refresh_interval(seconds: u64) {
+    REFRESH_INTERVAL_SECS.store(seconds.max(1), Ordering::Relaxed);
+}
+
+fn window_duration(time_range: &str) -> chrono::Duration {
+    match time_range {
+        "1h" => chrono::Duration::hours(1),
+        "24h" => chrono::Duration::hours(24),
+        _ => chrono::Duration::minutes(5),
+    }
+}
+
+/// Slice the ring buffers down to the requested window and reduce each
+/// session's samples to min/max/avg response time and total throughput.
+pub async fn rollup(time_range: &str) -> Vec<MetricRollup> {
+    let since = Utc::now() - window_duration(time_range);
+    let samples = METRICS_STORE.window(since).await;
+
+    let mut by_session: HashMap<String, Vec<MetricSample>> = HashMap::new();
+    for (session_name, sample) in samples {
+        by_session.entry(session_name).or_default().push(sample);
+    }
+
+    by_session
+        .into_iter()
+        .map(|(session_name, samples)| {
+            let sample_count = samples.len();
+            let min_response_time = samples
+                .iter()
+                .map(|s| s.response_time)
+                .fold(f64::INFINITY, f64::min);
+            let max_response_time = samples
+                .iter()
+                .map(|s| s.response_time)
+                .fold(f64::NEG_INFINITY, f64::max);
+            let avg_response_time =
+                samples.iter().map(|s| s.response_time).sum::<f64>() / sample_count as f64;
+            let total_prompts_per_minute = samples.iter().map(|s| s.prompts_per_minute).sum();
+
+            MetricRollup {
+                session_name,
+                min_response_time,
+                max_response_time,
+                avg_response_time,
+                total_prompts_per_minute,
+                sample_count,
+            }
+        })
+        .collect()
+}
+
+/// Current aggregates across all sessions for the dashboard header.
+pub async fn summary() -> MetricsSummary {
+    let since = Utc::now() - chrono::Duration::minutes(5);
+    let recent = METRICS_STORE.window(since).await;
+
+    let session_count = recent
+        .iter()
+        .map(|(session, _)| session.clone())
+        .collect::<HashSet<_>>()
+        .len();
+    let avg_response_time = if recent.is_empty() {
+        0.0
+    } else {
+        recent.iter().map(|(_, s)| s.response_time).sum::<f64>() / recent.len() as f64
+    };
+    let total_prompts_per_minute = recent.iter().map(|(_, s)| s.prompts_per_minute).sum();
+
+    MetricsSummary {
+        session_count,
+        avg_response_time,
+        total_prompts_per_minute,
+    }
+}
+
+/// One polling tick: query every active session's controller for its
+/// current response time / throughput, push it into the ring buffer,
+/// and stream it live through `EventManager::emit_metric_update`.
+pub async fn poll_once(runtime: &PythonRuntime) {
+    let Ok(sessions_value) = runtime.call("get_all_sessions", serde_json::json!({})).await else {
+        return;
+    };
+    let Ok(sessions) = serde_json::from_value::<Vec<SessionInfo>>(sessions_value) else {
+        return;
+    };
+
+    for session in sessions {
+        let Ok(metrics_value) = runtime
+            .call(
+                "get_controller_metrics",
+                serde_json::json!({ "session_name": session.session_name }),
+            )
+            .await
+        else {
+            continue;
+        };
+
+        let response_time = metrics_value.get("response_time").and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let prompts_per_minute = metrics_value
+            .get("prompts_per_minute")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.0);
+
+        let sample = MetricSample {
+            timestamp: Utc::now(),
+            response_time,
+            prompts_per_minute,
+        };
+        METRICS_STORE.push(&session.session_name, sample).await;
+        EVENT_MANAGER
+            .emit_metric_update(&session.session_name, response_time, prompts_per_minute)
+            .await;
+    }
+}
@@ -1,8 +1,14 @@
+use std::collections::HashMap;
 use std::process::Command;
 use serde::{Deserialize, Serialize};
-use tauri::command;
+use tauri::{command, AppHandle, GlobalShortcutManager, State};
 use chrono::{DateTime, Utc};
 
+use crate::controller_state::{self, ControllerState, ControllerStateEntry, CONTROLLER_STATE_CACHE};
+use crate::error::{BridgeError, ERROR_CHANNEL};
+use crate::hotkeys::HotkeyBindings;
+use crate::python_runtime::PythonRuntime;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SessionInfo {
     pub session_name: String,
@@ -35,14 +41,8 @@ pub struct AppConfig {
     pub log_level: String,
     pub auto_refresh: bool,
     pub refresh_interval: i32,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct MetricData {
-    pub timestamp: DateTime<Utc>,
-    pub response_time: f64,
-    pub prompts_per_minute: f64,
-    pub session_name: String,
+    #[serde(default)]
+    pub hotkeys: HotkeyBindings,
 }
 
 // User Experience related structures
@@ -100,176 +100,167 @@ pub struct DocumentationInfo {
     pub size_kb: i32,
 }
 
-fn execute_python_script(script: &str) -> Result<String, String> {
+async fn execute_python_script(script: &str) -> Result<String, BridgeError> {
+    let started_at = Utc::now();
     let output = Command::new("python")
         .args(["-c", script])
         .output()
-        .map_err(|e| format!("Failed to execute command: {}", e))?;
+        .map_err(|e| report(BridgeError::Io(e.to_string())))?;
+
+    let status = crate::session_log::normalize_exit_status(&output.status);
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+    let _ = crate::session_log::append("system", "execute_python_script", &serde_json::json!({}), started_at, &stdout, &status).await;
 
     if !output.status.success() {
-        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        return Err(report(BridgeError::PythonExit {
+            code: output.status.code().unwrap_or(-1),
+            stderr,
+        }));
     }
 
-    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    Ok(stdout)
 }
 
-#[command]
-pub async fn get_all_sessions() -> Result<Vec<SessionInfo>, String> {
-    let script = r#"
-import sys
-import os
-sys.path.append('.')
-sys.path.append('..')
-
-try:
-    from libs.core.session_manager import SessionManager
-    sm = SessionManager()
-    sessions = sm.get_all_sessions()
-
-    import json
-    result = []
-    for s in sessions:
-        windows = []
-        if hasattr(s, 'windows') and s.windows:
-            for w in s.windows:
-                panes = []
-                if hasattr(w, 'panes') and w.panes:
-                    for p in w.panes:
-                        panes.append({
-                            'command': getattr(p, 'command', ''),
-                            'is_claude': getattr(p, 'is_claude', False),
-                            'is_controller': getattr(p, 'is_controller', False)
-                        })
-                windows.append({
-                    'index': getattr(w, 'index', 0),
-                    'name': getattr(w, 'name', ''),
-                    'panes': panes
-                })
-
-        result.append({
-            'session_name': getattr(s, 'session_name', ''),
-            'project_name': getattr(s, 'project_name', ''),
-            'status': getattr(s, 'status', 'unknown'),
-            'template': getattr(s, 'template', ''),
-            'windows': windows
-        })
-
-    print(json.dumps(result))
-except Exception as e:
-    print(json.dumps({'error': str(e)}))
-"#;
-
-    let result = execute_python_script(script)?;
-
-    // 에러 체크
-    if result.contains("\"error\"") {
-        return Err(format!("Python error: {}", result));
-    }
-
-    serde_json::from_str(&result)
-        .map_err(|e| format!("Failed to parse JSON: {}", e))
+/// Push a failing command's error onto the global channel so the UI can
+/// toast it regardless of which command triggered it, then hand the
+/// same error back to the `?` operator.
+fn report(error: BridgeError) -> BridgeError {
+    ERROR_CHANNEL.report(error.clone());
+    error
 }
 
 #[command]
-pub async fn get_controller_status(session_name: String) -> Result<String, String> {
-    let script = format!(r#"
-import sys
-sys.path.append('.')
-sys.path.append('..')
-
-try:
-    from libs.core.claude_manager import ClaudeManager
-    cm = ClaudeManager()
-    controller = cm.get_controller('{}')
-    if controller:
-        if controller.is_running:
-            print('Active')
-        else:
-            print('Ready')
-    else:
-        print('Not Available')
-except Exception as e:
-    print('Error')
-"#, session_name);
-
-    execute_python_script(&script)
+pub async fn get_all_sessions(runtime: State<'_, PythonRuntime>) -> Result<Vec<SessionInfo>, BridgeError> {
+    let value = runtime.call("get_all_sessions", serde_json::json!({})).await?;
+    serde_json::from_value(value).map_err(BridgeError::from)
 }
 
 #[command]
-pub async fn start_controller(session_name: String) -> Result<bool, String> {
-    let script = format!(r#"
-import sys
-sys.path.append('.')
-sys.path.append('..')
-
-try:
-    from libs.core.claude_manager import ClaudeManager
-    cm = ClaudeManager()
-    controller = cm.get_controller('{}')
-    if controller:
-        result = controller.start()
-        print(result)
-    else:
-        print(False)
-except Exception as e:
-    print(False)
-"#, session_name);
-
-    let result = execute_python_script(&script)?;
-    Ok(result.trim() == "True")
+pub async fn get_controller_status(
+    session_name: String,
+    runtime: State<'_, PythonRuntime>,
+) -> Result<String, BridgeError> {
+    let value = runtime
+        .call("get_controller_status", serde_json::json!({ "session_name": session_name }))
+        .await?;
+    serde_json::from_value(value).map_err(BridgeError::from)
 }
 
 #[command]
-pub async fn stop_controller(session_name: String) -> Result<bool, String> {
-    let script = format!(r#"
-import sys
-sys.path.append('.')
-sys.path.append('..')
-
-try:
-    from libs.core.claude_manager import ClaudeManager
-    cm = ClaudeManager()
-    controller = cm.get_controller('{}')
-    if controller:
-        result = controller.stop()
-        print(result)
-    else:
-        print(False)
-except Exception as e:
-    print(False)
-"#, session_name);
-
-    let result = execute_python_script(&script)?;
-    Ok(result.trim() == "True")
+pub async fn start_controller(
+    session_name: String,
+    runtime: State<'_, PythonRuntime>,
+) -> Result<bool, BridgeError> {
+    controller_state::transition(&session_name, ControllerState::Starting).await?;
+
+    match runtime
+        .call("start_controller", serde_json::json!({ "session_name": session_name }))
+        .await
+    {
+        Ok(value) => {
+            let started: bool = serde_json::from_value(value).map_err(BridgeError::from)?;
+            let next = if started {
+                ControllerState::Running
+            } else {
+                ControllerState::Failed("start_controller returned false".to_string())
+            };
+            controller_state::transition(&session_name, next).await?;
+            Ok(started)
+        }
+        Err(e) => {
+            let _ = controller_state::transition(&session_name, ControllerState::Failed(e.to_string())).await;
+            Err(e)
+        }
+    }
 }
 
 #[command]
-pub async fn restart_claude_pane(session_name: String) -> Result<bool, String> {
-    let script = format!(r#"
-import sys
-sys.path.append('.')
-sys.path.append('..')
+pub async fn stop_controller(
+    session_name: String,
+    runtime: State<'_, PythonRuntime>,
+) -> Result<bool, BridgeError> {
+    controller_state::transition(&session_name, ControllerState::Stopping).await?;
+
+    match runtime
+        .call("stop_controller", serde_json::json!({ "session_name": session_name }))
+        .await
+    {
+        Ok(value) => {
+            let stopped: bool = serde_json::from_value(value).map_err(BridgeError::from)?;
+            let next = if stopped {
+                ControllerState::Stopped
+            } else {
+                ControllerState::Failed("stop_controller returned false".to_string())
+            };
+            controller_state::transition(&session_name, next).await?;
+            Ok(stopped)
+        }
+        Err(e) => {
+            let _ = controller_state::transition(&session_name, ControllerState::Failed(e.to_string())).await;
+            Err(e)
+        }
+    }
+}
 
-try:
-    from libs.core.claude_manager import ClaudeManager
-    cm = ClaudeManager()
-    controller = cm.get_controller('{}')
-    if controller:
-        result = controller.restart_claude_pane()
-        print(result)
-    else:
-        print(False)
-except Exception as e:
-    print(False)
-"#, session_name);
+#[command]
+pub async fn restart_claude_pane(
+    session_name: String,
+    runtime: State<'_, PythonRuntime>,
+) -> Result<bool, BridgeError> {
+    // 재시작은 실행 중인 컨트롤러를 멈췄다가 다시 띄우는 것이므로
+    // start_controller/stop_controller와 같은 상태 전이를 거친다. 다만
+    // CONTROLLER_STATE_CACHE는 메모리 전용이라 재시작할 때마다 비어 있을
+    // 수 있고, 그럴 경우 current()는 Idle을 돌려준다 — 이 앱 세션에서
+    // UI로 한 번도 start하지 않은 세션이 흔한 경우다. 이미 Running이
+    // 아니라면 정지 단계는 건너뛰고 곧바로 Starting으로 넘어간다.
+    if controller_state::current(&session_name).await == ControllerState::Running {
+        controller_state::transition(&session_name, ControllerState::Stopping).await?;
+        controller_state::transition(&session_name, ControllerState::Stopped).await?;
+    }
+    controller_state::transition(&session_name, ControllerState::Starting).await?;
+
+    match runtime
+        .call("restart_claude_pane", serde_json::json!({ "session_name": session_name }))
+        .await
+    {
+        Ok(value) => {
+            let restarted: bool = serde_json::from_value(value).map_err(BridgeError::from)?;
+            let next = if restarted {
+                ControllerState::Running
+            } else {
+                ControllerState::Failed("restart_claude_pane returned false".to_string())
+            };
+            controller_state::transition(&session_name, next).await?;
+            Ok(restarted)
+        }
+        Err(e) => {
+            let _ = controller_state::transition(&session_name, ControllerState::Failed(e.to_string())).await;
+            Err(e)
+        }
+    }
+}
 
-    let result = execute_python_script(&script)?;
-    Ok(result.trim() == "True")
+#[command]
+pub async fn get_all_controller_states() -> Result<HashMap<String, ControllerStateEntry>, BridgeError> {
+    Ok(CONTROLLER_STATE_CACHE.entries().await)
 }
 
+/// Key `get_app_config`/`save_app_config` share in `CONFIG_CACHE` for the
+/// Python-computed half of `AppConfig` (the live-overlaid fields —
+/// `auto_refresh`, `refresh_interval`, `hotkeys` — are reapplied on every
+/// read regardless of what's cached).
+const APP_CONFIG_CACHE_KEY: &str = "app_config";
+
 #[command]
-pub async fn get_app_config() -> Result<AppConfig, String> {
-    let script = r#"
+pub async fn get_app_config() -> Result<AppConfig, BridgeError> {
+    // get_or_compute gives this a warm-start (CONFIG_CACHE persists to
+    // disk) and single-flights concurrent misses instead of kicking off
+    // the Python script once per caller.
+    let result = crate::cache::CONFIG_CACHE
+        .get_or_compute(APP_CONFIG_CACHE_KEY.to_string(), None, || async {
+            let script = r#"
 import sys
 sys.path.append('.')
 sys.path.append('..')
@@ -296,57 +287,86 @@ except Exception as e:
     print(json.dumps({'error': str(e)}))
 "#;
 
-    let result = execute_python_script(script)?;
+            execute_python_script(script)
+                .await
+                .unwrap_or_else(|e| serde_json::json!({ "error": e.to_string() }).to_string())
+        })
+        .await;
 
     if result.contains("\"error\"") {
-        return Err(format!("Python error: {}", result));
+        // 실패한 결과를 1시간 동안 그대로 캐시하고 싶지 않으므로, 다음
+        // 호출이 다시 계산하도록 바로 지운다.
+        crate::cache::CONFIG_CACHE.remove(APP_CONFIG_CACHE_KEY).await;
+        return Err(report(BridgeError::PythonRaised { traceback: result }));
     }
 
-    serde_json::from_str(&result)
-        .map_err(|e| format!("Failed to parse config JSON: {}", e))
+    let mut config: AppConfig = match serde_json::from_str(&result) {
+        Ok(config) => config,
+        Err(e) => {
+            // 파싱 불가능한 결과도 에러와 마찬가지로 다음 시도가 다시
+            // 계산하도록 지운다 — 그대로 두면 같은 깨진 값을 TTL이 끝날
+            // 때까지, 게다가 sled에 영속된 채로 계속 돌려주게 된다.
+            crate::cache::CONFIG_CACHE.remove(APP_CONFIG_CACHE_KEY).await;
+            return Err(BridgeError::from(e));
+        }
+    };
+    config.auto_refresh = crate::metrics::AUTO_REFRESH.load(std::sync::atomic::Ordering::Relaxed);
+    config.refresh_interval = crate::metrics::REFRESH_INTERVAL_SECS.load(std::sync::atomic::Ordering::Relaxed) as i32;
+    config.hotkeys = crate::hotkeys::current_bindings();
+    Ok(config)
 }
 
 #[command]
-pub async fn save_app_config(config: AppConfig) -> Result<bool, String> {
+pub async fn save_app_config(config: AppConfig, app: AppHandle) -> Result<bool, BridgeError> {
     // 설정 저장 로직 (현재는 더미)
     println!("Saving config: {:?}", config);
+
+    // 다음 get_app_config가 방금 저장한 값을 warm-start로 돌려받도록
+    // 캐시를 갱신한다. 라이브 오버레이 필드(auto_refresh 등)도 같이
+    // 들어가지만 읽을 때 항상 덮어써지므로 무해하다.
+    if let Ok(serialized) = serde_json::to_string(&config) {
+        crate::cache::CONFIG_CACHE
+            .set(APP_CONFIG_CACHE_KEY.to_string(), serialized)
+            .await;
+    }
+
+    crate::metrics::set_auto_refresh(config.auto_refresh);
+    crate::metrics::set_refresh_interval(config.refresh_interval.max(1) as u64);
+
+    crate::hotkeys::set_bindings(config.hotkeys);
+    let _ = app.global_shortcut_manager().unregister_all();
+    crate::hotkeys::register_global_shortcuts(&app);
+
     Ok(true)
 }
 
 #[command]
-pub async fn get_session_logs(session_name: String, limit: Option<i32>) -> Result<Vec<String>, String> {
-    let limit = limit.unwrap_or(100);
-
-    // 더미 로그 데이터 (실제로는 Python에서 로그 파일 읽기)
-    let mut logs = Vec::new();
-    for i in 0..limit {
-        logs.push(format!("[{:02}:{:02}:{:02}] Log entry {} for session {}",
-            (i / 3600) % 24, (i / 60) % 60, i % 60, i, session_name));
-    }
+pub async fn get_session_logs(session_name: String, limit: Option<i32>) -> Result<Vec<String>, BridgeError> {
+    let limit = limit.unwrap_or(100).max(0) as usize;
 
-    Ok(logs)
+    crate::session_log::tail(&session_name, limit)
+        .map_err(|e| report(BridgeError::Io(e.to_string())))
 }
 
 #[command]
-pub async fn get_metrics_data(_time_range: String) -> Result<Vec<MetricData>, String> {
-    // 더미 메트릭 데이터
-    let mut metrics = Vec::new();
-    let now = Utc::now();
-
-    for i in 0..20 {
-        metrics.push(MetricData {
-            timestamp: now - chrono::Duration::minutes(i),
-            response_time: 100.0 + (i as f64) * 10.0,
-            prompts_per_minute: 5.0 + (i as f64) * 0.5,
-            session_name: "example".to_string(),
-        });
-    }
+pub async fn clear_session_logs(session_name: String) -> Result<bool, BridgeError> {
+    crate::session_log::clear(&session_name)
+        .map(|_| true)
+        .map_err(|e| report(BridgeError::Io(e.to_string())))
+}
 
-    Ok(metrics)
+#[command]
+pub async fn get_metrics_data(time_range: String) -> Result<Vec<crate::metrics::MetricRollup>, BridgeError> {
+    Ok(crate::metrics::rollup(&time_range).await)
 }
 
 #[command]
-pub async fn setup_tmux_session(session_name: Option<String>) -> Result<bool, String> {
+pub async fn get_metrics_summary() -> Result<crate::metrics::MetricsSummary, BridgeError> {
+    Ok(crate::metrics::summary().await)
+}
+
+#[command]
+pub async fn setup_tmux_session(session_name: Option<String>) -> Result<bool, BridgeError> {
     let script = match session_name {
         Some(name) => format!(r#"
 import sys
@@ -376,12 +396,12 @@ except Exception as e:
 "#.to_string()
     };
 
-    let result = execute_python_script(&script)?;
+    let result = execute_python_script(&script).await?;
     Ok(result.trim() == "True")
 }
 
 #[command]
-pub async fn teardown_tmux_session(session_name: Option<String>) -> Result<bool, String> {
+pub async fn teardown_tmux_session(session_name: Option<String>) -> Result<bool, BridgeError> {
     let script = match session_name {
         Some(name) => format!(r#"
 import sys
@@ -411,13 +431,13 @@ except Exception as e:
 "#.to_string()
     };
 
-    let result = execute_python_script(&script)?;
+    let result = execute_python_script(&script).await?;
     Ok(result.trim() == "True")
 }
 
 // User Experience Commands
 #[command]
-pub async fn run_troubleshooting_diagnosis() -> Result<Vec<TroubleshootingIssue>, String> {
+pub async fn run_troubleshooting_diagnosis() -> Result<Vec<TroubleshootingIssue>, BridgeError> {
     let script = r#"
 import sys
 sys.path.append('.')
@@ -445,18 +465,18 @@ except Exception as e:
     print(json.dumps({'error': str(e)}))
 "#;
 
-    let result = execute_python_script(script)?;
+    let result = execute_python_script(script).await?;
     
     if result.contains("\"error\"") {
-        return Err(format!("Python error: {}", result));
+        return Err(report(BridgeError::PythonRaised { traceback: result }));
     }
 
     serde_json::from_str(&result)
-        .map_err(|e| format!("Failed to parse troubleshooting result: {}", e))
+        .map_err(BridgeError::from)
 }
 
 #[command]
-pub async fn get_troubleshooting_guide(issue_id: String) -> Result<Vec<TroubleshootingStep>, String> {
+pub async fn get_troubleshooting_guide(issue_id: String) -> Result<Vec<TroubleshootingStep>, BridgeError> {
     let script = format!(r#"
 import sys
 sys.path.append('.')
@@ -485,18 +505,18 @@ except Exception as e:
     print(json.dumps({{'error': str(e)}}))
 "#, issue_id);
 
-    let result = execute_python_script(&script)?;
+    let result = execute_python_script(&script).await?;
     
     if result.contains("\"error\"") {
-        return Err(format!("Python error: {}", result));
+        return Err(report(BridgeError::PythonRaised { traceback: result }));
     }
 
     serde_json::from_str(&result)
-        .map_err(|e| format!("Failed to parse troubleshooting guide: {}", e))
+        .map_err(BridgeError::from)
 }
 
 #[command]
-pub async fn execute_troubleshooting_fix(issue_id: String, auto_approve: bool) -> Result<TroubleshootingResult, String> {
+pub async fn execute_troubleshooting_fix(issue_id: String, auto_approve: bool) -> Result<TroubleshootingResult, BridgeError> {
     let script = format!(r#"
 import sys
 sys.path.append('.')
@@ -520,18 +540,18 @@ except Exception as e:
     print(json.dumps({{'error': str(e)}}))
 "#, issue_id, auto_approve);
 
-    let result = execute_python_script(&script)?;
+    let result = execute_python_script(&script).await?;
     
     if result.contains("\"error\"") {
-        return Err(format!("Python error: {}", result));
+        return Err(report(BridgeError::PythonRaised { traceback: result }));
     }
 
     serde_json::from_str(&result)
-        .map_err(|e| format!("Failed to parse fix result: {}", e))
+        .map_err(BridgeError::from)
 }
 
 #[command]
-pub async fn generate_documentation() -> Result<Vec<DocumentationInfo>, String> {
+pub async fn generate_documentation() -> Result<Vec<DocumentationInfo>, BridgeError> {
     let script = r#"
 import sys
 sys.path.append('.')
@@ -559,18 +579,18 @@ except Exception as e:
     print(json.dumps({'error': str(e)}))
 "#;
 
-    let result = execute_python_script(script)?;
+    let result = execute_python_script(script).await?;
     
     if result.contains("\"error\"") {
-        return Err(format!("Python error: {}", result));
+        return Err(report(BridgeError::PythonRaised { traceback: result }));
     }
 
     serde_json::from_str(&result)
-        .map_err(|e| format!("Failed to parse documentation result: {}", e))
+        .map_err(BridgeError::from)
 }
 
 #[command]
-pub async fn get_setup_steps() -> Result<Vec<SetupStep>, String> {
+pub async fn get_setup_steps() -> Result<Vec<SetupStep>, BridgeError> {
     let script = r#"
 import sys
 sys.path.append('.')
@@ -598,18 +618,18 @@ except Exception as e:
     print(json.dumps({'error': str(e)}))
 "#;
 
-    let result = execute_python_script(script)?;
+    let result = execute_python_script(script).await?;
     
     if result.contains("\"error\"") {
-        return Err(format!("Python error: {}", result));
+        return Err(report(BridgeError::PythonRaised { traceback: result }));
     }
 
     serde_json::from_str(&result)
-        .map_err(|e| format!("Failed to parse setup steps: {}", e))
+        .map_err(BridgeError::from)
 }
 
 #[command]
-pub async fn run_setup_step(step_id: String, interactive: bool) -> Result<SetupResult, String> {
+pub async fn run_setup_step(step_id: String, interactive: bool) -> Result<SetupResult, BridgeError> {
     let script = format!(r#"
 import sys
 sys.path.append('.')
@@ -632,18 +652,18 @@ except Exception as e:
     print(json.dumps({{'error': str(e)}}))
 "#, step_id, interactive);
 
-    let result = execute_python_script(&script)?;
+    let result = execute_python_script(&script).await?;
     
     if result.contains("\"error\"") {
-        return Err(format!("Python error: {}", result));
+        return Err(report(BridgeError::PythonRaised { traceback: result }));
     }
 
     serde_json::from_str(&result)
-        .map_err(|e| format!("Failed to parse setup result: {}", e))
+        .map_err(BridgeError::from)
 }
 
 #[command]
-pub async fn get_system_health() -> Result<serde_json::Value, String> {
+pub async fn get_system_health() -> Result<serde_json::Value, BridgeError> {
     let script = r#"
 import sys
 sys.path.append('.')
@@ -660,40 +680,42 @@ except Exception as e:
     print(json.dumps({'error': str(e), 'status': 'unhealthy'}))
 "#;
 
-    let result = execute_python_script(script)?;
-    
+    let result = execute_python_script(script).await?;
+
     if result.contains("\"error\"") && !result.contains("status") {
-        return Err(format!("Python error: {}", result));
+        return Err(report(BridgeError::PythonRaised { traceback: result }));
     }
 
-    serde_json::from_str(&result)
-        .map_err(|e| format!("Failed to parse health status: {}", e))
+    let mut health: serde_json::Value = serde_json::from_str(&result).map_err(BridgeError::from)?;
+    if let Some(map) = health.as_object_mut() {
+        map.insert(
+            "session_log_bytes".to_string(),
+            serde_json::json!(crate::session_log::total_log_bytes()),
+        );
+    }
+    Ok(health)
 }
 
 #[allow(dead_code)]
-pub async fn start_all_controllers() -> Result<i32, String> {
-    let sessions = get_all_sessions().await?;
-    let mut success_count = 0;
+pub async fn start_all_controllers(runtime: State<'_, PythonRuntime>) -> Result<i32, BridgeError> {
+    let sessions = get_all_sessions(runtime.clone()).await?;
+    let results = futures::future::join_all(sessions.into_iter().map(|session| {
+        let runtime = runtime.clone();
+        async move { start_controller(session.session_name, runtime).await }
+    }))
+    .await;
 
-    for session in sessions {
-        if let Ok(true) = start_controller(session.session_name).await {
-            success_count += 1;
-        }
-    }
-
-    Ok(success_count)
+    Ok(results.into_iter().filter(|r| matches!(r, Ok(true))).count() as i32)
 }
 
 #[allow(dead_code)]
-pub async fn stop_all_controllers() -> Result<i32, String> {
-    let sessions = get_all_sessions().await?;
-    let mut success_count = 0;
-
-    for session in sessions {
-        if let Ok(true) = stop_controller(session.session_name).await {
-            success_count += 1;
-        }
-    }
-
-    Ok(success_count)
+pub async fn stop_all_controllers(runtime: State<'_, PythonRuntime>) -> Result<i32, BridgeError> {
+    let sessions = get_all_sessions(runtime.clone()).await?;
+    let results = futures::future::join_all(sessions.into_iter().map(|session| {
+        let runtime = runtime.clone();
+        async move { stop_controller(session.session_name, runtime).await }
+    }))
+    .await;
+
+    Ok(results.into_iter().filter(|r| matches!(r, Ok(true))).count() as i32)
 }
@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, Command};
+use tokio::sync::{oneshot, Mutex, Semaphore};
+use tokio::time::{timeout, Duration};
+
+use chrono::Utc;
+
+use crate::error::{BridgeError, ERROR_CHANNEL};
+use crate::session_log;
+
+const WORKER_DRIVER: &str = "python/worker_driver.py";
+const CALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Serialize)]
+struct RpcRequest<'a> {
+    id: u64,
+    method: &'a str,
+    params: Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcResponse {
+    #[allow(dead_code)]
+    id: u64,
+    #[serde(default)]
+    result: Option<Value>,
+    #[serde(default)]
+    error: Option<Value>,
+}
+
+type Pending = Arc<Mutex<HashMap<u64, oneshot::Sender<RpcResponse>>>>;
+
+/// One warm Python interpreter that has already imported the manager
+/// classes and is waiting for JSON-RPC requests on stdin.
+struct Worker {
+    child: Child,
+    stdin: ChildStdin,
+    pending: Pending,
+    /// Set once the reader task observes stdout EOF, i.e. the process
+    /// actually exited. Distinguishes "this worker is confirmed dead" from
+    /// "one call routed to it happened to be slow" so an isolated timeout
+    /// on a still-healthy worker doesn't respawn the slot out from under
+    /// every other call multiplexed onto it.
+    dead: Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl Worker {
+    async fn spawn() -> std::io::Result<Self> {
+        let mut child = Command::new("python")
+            .arg(WORKER_DRIVER)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()?;
+
+        let stdin = child.stdin.take().expect("worker spawned with piped stdin");
+        let stdout = child.stdout.take().expect("worker spawned with piped stdout");
+        let pending: Pending = Arc::new(Mutex::new(HashMap::new()));
+        let dead = Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+        let reader_pending = pending.clone();
+        let reader_dead = dead.clone();
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(stdout).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                let Ok(response) = serde_json::from_str::<RpcResponse>(&line) else {
+                    continue;
+                };
+                if let Some(sender) = reader_pending.lock().await.remove(&response.id) {
+                    let _ = sender.send(response);
+                }
+            }
+            // stdout closed: the worker exited. Mark it dead so a caller
+            // still waiting on it knows to respawn rather than assume its
+            // own call was simply slow.
+            reader_dead.store(true, Ordering::Relaxed);
+        });
+
+        Ok(Self { child, stdin, pending, dead })
+    }
+}
+
+/// A pool of long-lived Python workers reused across bridge commands,
+/// multiplexed over a line-delimited JSON-RPC protocol so concurrent
+/// commands can share one warm interpreter per pool slot.
+pub struct PythonRuntime {
+    workers: Vec<Mutex<Worker>>,
+    available: Semaphore,
+    next_id: AtomicU64,
+}
+
+impl PythonRuntime {
+    pub async fn new(pool_size: usize) -> std::io::Result<Self> {
+        let mut workers = Vec::with_capacity(pool_size);
+        for _ in 0..pool_size {
+            workers.push(Mutex::new(Worker::spawn().await?));
+        }
+
+        Ok(Self {
+            workers,
+            available: Semaphore::new(pool_size),
+            next_id: AtomicU64::new(1),
+        })
+    }
+
+    /// Dispatch `method` with `params` to the next worker in round-robin order, logging
+    /// the call (start time, params, outcome) to the session's log file
+    /// and streaming the new line live through `EventManager`.
+    pub async fn call(&self, method: &str, params: Value) -> Result<Value, BridgeError> {
+        let started_at = Utc::now();
+        let session_name = params
+            .get("session_name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("system")
+            .to_string();
+
+        let outcome = self.dispatch(method, params.clone()).await;
+
+        let status = match &outcome {
+            Ok(_) => "ok".to_string(),
+            Err(e) => format!("error: {}", e),
+        };
+        let result = match &outcome {
+            Ok(v) => v.to_string(),
+            Err(_) => String::new(),
+        };
+        let _ = session_log::append(&session_name, method, &params, started_at, &result, &status).await;
+
+        outcome
+    }
+
+    /// Dispatch `method` with `params` to the next worker in round-robin
+    /// order (busy or not) and await its response. A worker whose process
+    /// has exited is transparently respawned; a call that times out on a
+    /// worker that is still alive is only cancelled, and that worker is not
+    /// respawned. The failing call itself is reported as an error rather than
+    /// retried so a crash can't poison the whole pool with an infinite loop.
+    ///
+    /// The worker's lock is held only long enough to write the request
+    /// line — not for the whole round-trip — so several calls routed to
+    /// the same worker can have responses pending at once instead of
+    /// serializing one-at-a-time on that pool slot.
+    async fn dispatch(&self, method: &str, params: Value) -> Result<Value, BridgeError> {
+        let _permit = self
+            .available
+            .acquire()
+            .await
+            .expect("runtime semaphore is never closed");
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let idx = (id as usize) % self.workers.len();
+
+        let rx = {
+            let mut worker = self.workers[idx].lock().await;
+
+            let (tx, rx) = oneshot::channel();
+            worker.pending.lock().await.insert(id, tx);
+
+            let request = RpcRequest { id, method, params };
+            let mut line = match serde_json::to_vec(&request) {
+                Ok(line) => line,
+                Err(e) => {
+                    worker.pending.lock().await.remove(&id);
+                    return Err(self.report(BridgeError::from(e)));
+                }
+            };
+            line.push(b'\n');
+
+            if worker.stdin.write_all(&line).await.is_err() {
+                worker.pending.lock().await.remove(&id);
+                self.respawn(&mut worker).await?;
+                return Err(self.report(BridgeError::Protocol(format!(
+                    "worker for '{}' had died; pool slot respawned",
+                    method
+                ))));
+            }
+
+            rx
+        };
+
+        match timeout(CALL_TIMEOUT, rx).await {
+            Ok(Ok(response)) => match response.error {
+                Some(error) => Err(self.report(BridgeError::PythonRaised {
+                    traceback: error.to_string(),
+                })),
+                None => Ok(response.result.unwrap_or(Value::Null)),
+            },
+            Ok(Err(_)) => {
+                // The sender was dropped without a response, which only
+                // happens when the worker this call was routed to got
+                // replaced (its old pending map, senders included, was
+                // dropped on respawn). Whoever detected the death already
+                // respawned the slot — don't do it again here, or we'd
+                // kill the perfectly healthy worker that just took its place.
+                Err(self.report(BridgeError::Protocol(format!(
+                    "worker closed its pipe while handling '{}'",
+                    method
+                ))))
+            }
+            Err(_) => {
+                let mut worker = self.workers[idx].lock().await;
+                worker.pending.lock().await.remove(&id);
+
+                if worker.dead.load(Ordering::Relaxed) {
+                    self.respawn(&mut worker).await?;
+                    return Err(self.report(BridgeError::Protocol(format!(
+                        "worker for '{}' had died; pool slot respawned",
+                        method
+                    ))));
+                }
+
+                // The worker is still alive and may have other calls
+                // in flight on it — this one was just slow, so cancel
+                // only this call instead of respawning the whole slot
+                // out from under its healthy siblings.
+                Err(self.report(BridgeError::Protocol(format!(
+                    "timed out waiting for '{}'",
+                    method
+                ))))
+            }
+        }
+    }
+
+    async fn respawn(&self, worker: &mut Worker) -> Result<(), BridgeError> {
+        let mut fresh = Worker::spawn()
+            .await
+            .map_err(|e| self.report(BridgeError::Spawn(e.to_string())))?;
+        std::mem::swap(worker, &mut fresh);
+        let _ = fresh.child.start_kill();
+        Ok(())
+    }
+
+    fn report(&self, error: BridgeError) -> BridgeError {
+        ERROR_CHANNEL.report(error.clone());
+        error
+    }
+}
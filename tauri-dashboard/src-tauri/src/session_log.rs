@@ -0,0 +1,112 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::events::EVENT_MANAGER;
+
+const LOG_DIR: &str = "logs";
+const MAX_LOG_BYTES: u64 = 2 * 1024 * 1024;
+
+/// One recorded interpreter invocation, appended as a single JSON line
+/// to `logs/<session_name>.log`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoggedCommand {
+    pub method: String,
+    pub params: Value,
+    pub started_at: DateTime<Utc>,
+    pub result: String,
+    pub status: String,
+}
+
+/// Normalizes `ExitStatus::to_string()`, which renders "exit code: N" on
+/// Windows but "exit status: N" on Unix, to one stable form for logs.
+pub fn normalize_exit_status(status: &std::process::ExitStatus) -> String {
+    format!("exit code: {}", status.code().unwrap_or(-1))
+}
+
+fn log_path(session_name: &str) -> PathBuf {
+    PathBuf::from(LOG_DIR).join(format!("{}.log", session_name))
+}
+
+fn rotate_if_needed(path: &PathBuf) -> std::io::Result<()> {
+    let Ok(meta) = fs::metadata(path) else {
+        return Ok(());
+    };
+    if meta.len() > MAX_LOG_BYTES {
+        let rotated = PathBuf::from(format!("{}.1", path.display()));
+        let _ = fs::remove_file(&rotated);
+        fs::rename(path, rotated)?;
+    }
+    Ok(())
+}
+
+/// Append one command record to the session's log file, rotating it
+/// first if it has grown past `MAX_LOG_BYTES`, then stream the new line
+/// live through `EventManager::emit_log_update`.
+pub async fn append(
+    session_name: &str,
+    method: &str,
+    params: &Value,
+    started_at: DateTime<Utc>,
+    result: &str,
+    status: &str,
+) -> std::io::Result<()> {
+    fs::create_dir_all(LOG_DIR)?;
+    let path = log_path(session_name);
+    rotate_if_needed(&path)?;
+
+    let record = LoggedCommand {
+        method: method.to_string(),
+        params: params.clone(),
+        started_at,
+        result: result.to_string(),
+        status: status.to_string(),
+    };
+    let line = serde_json::to_string(&record).unwrap_or_default();
+
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    writeln!(file, "{}", line)?;
+
+    EVENT_MANAGER.emit_log_update(session_name, &line).await;
+    Ok(())
+}
+
+/// Tail the last `limit` lines of a session's real log file.
+pub fn tail(session_name: &str, limit: usize) -> std::io::Result<Vec<String>> {
+    let path = log_path(session_name);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let lines: Vec<String> = BufReader::new(File::open(&path)?)
+        .lines()
+        .collect::<std::io::Result<_>>()?;
+    let start = lines.len().saturating_sub(limit);
+    Ok(lines[start..].to_vec())
+}
+
+/// Remove a session's log file entirely.
+pub fn clear(session_name: &str) -> std::io::Result<()> {
+    let path = log_path(session_name);
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Total size, in bytes, of every session log file on disk. Used by
+/// `get_system_health` diagnostics.
+pub fn total_log_bytes() -> u64 {
+    let Ok(entries) = fs::read_dir(LOG_DIR) else {
+        return 0;
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|meta| meta.len())
+        .sum()
+}